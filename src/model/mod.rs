@@ -0,0 +1,3 @@
+pub mod network;
+pub mod population;
+pub mod training;
@@ -2,20 +2,66 @@ use serde::{Deserialize, Serialize};
 
 use super::training::{Datapoint, Dataset, Genetic, Label};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ActivationFunction {
     Linear,
     ReLU,
     Sigmoid,
     Softmax,
+    Tanh,
+    /// Leaky ReLU with a fixed negative slope (e.g. `0.01`).
+    LeakyReLU(f64),
+    /// Parametric ReLU: the same shape as `LeakyReLU`, but the slope is a
+    /// learnable parameter `Genetic::mutate` also perturbs.
+    PReLU(f64),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CostFunction {
     MSE,
     CCE,
 }
 
+/// Strategy used to seed a `Neuron`'s weights and bias.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum Initialization {
+    /// Uniform noise in `[-1, 1]`, regardless of layer width.
+    Uniform,
+    /// Normal distribution with std dev `sqrt(1 / fan_in)`, suited to
+    /// Sigmoid/Softmax layers.
+    Xavier,
+    /// Normal distribution with std dev `sqrt(2 / fan_in)`, suited to ReLU
+    /// layers.
+    He,
+}
+
+/// The dimensions of a layer's input/output tensor, tracked through the
+/// builder so convolutional and pooling layers can be chained alongside
+/// dense ones while `output_size` stays correct.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Shape {
+    pub height: usize,
+    pub width: usize,
+    pub channels: usize,
+}
+impl Shape {
+    pub fn new(height: usize, width: usize, channels: usize) -> Self {
+        Self {
+            height,
+            width,
+            channels,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.height * self.width * self.channels
+    }
+
+    fn index(&self, y: usize, x: usize, channel: usize) -> usize {
+        (x + y * self.width) * self.channels + channel
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Neuron {
     pub weights: Vec<f64>,
@@ -27,6 +73,65 @@ pub struct Neuron {
 pub struct Layer {
     pub neurons: Vec<Neuron>,
     pub function: ActivationFunction,
+    #[serde(skip)]
+    last_input: Vec<f64>,
+    #[serde(skip)]
+    last_output: Vec<f64>,
+    #[serde(skip)]
+    pending_weight_grads: Vec<Vec<f64>>,
+    #[serde(skip)]
+    pending_bias_grads: Vec<f64>,
+}
+
+/// A single learnable filter in a `Conv2D` layer.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Kernel {
+    /// Flattened `(kernel_size, kernel_size, in_channels)` weights.
+    pub weights: Vec<f64>,
+    pub bias: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Conv2D {
+    kernels: Vec<Kernel>,
+    kernel_size: usize,
+    stride: usize,
+    padding: usize,
+    function: ActivationFunction,
+    input_shape: Shape,
+    output_shape: Shape,
+    #[serde(skip)]
+    last_input: Vec<f64>,
+    #[serde(skip)]
+    last_pre_activation: Vec<f64>,
+    #[serde(skip)]
+    last_output: Vec<f64>,
+    #[serde(skip)]
+    pending_kernel_grads: Vec<Vec<f64>>,
+    #[serde(skip)]
+    pending_bias_grads: Vec<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MaxPool2D {
+    pool_size: usize,
+    stride: usize,
+    input_shape: Shape,
+    output_shape: Shape,
+    #[serde(skip)]
+    max_indices: Vec<usize>,
+    #[serde(skip)]
+    last_output: Vec<f64>,
+}
+
+/// A layer in the `Network` pipeline: either a fully-connected `Layer`, or
+/// one of the spatial layer kinds that operate on a `(height, width,
+/// channels)` tensor before it gets flattened into the dense stack.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum NetworkLayer {
+    Dense(Layer),
+    Conv2D(Conv2D),
+    MaxPool2D(MaxPool2D),
 }
 
 pub struct Construction;
@@ -34,8 +139,8 @@ pub struct Ready;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Network<Status = Construction> {
-    input_size: usize,
-    layers: Vec<Layer>,
+    shape: Shape,
+    layers: Vec<NetworkLayer>,
     cost_function: CostFunction,
     marker: std::marker::PhantomData<Status>,
 }
@@ -62,6 +167,58 @@ impl ActivationFunction {
                     .map(|&output| f64::exp(output) / partition)
                     .collect()
             }
+            ActivationFunction::Tanh => outputs.iter().map(|&output| output.tanh()).collect(),
+            ActivationFunction::LeakyReLU(slope) | ActivationFunction::PReLU(slope) => outputs
+                .iter()
+                .map(|&output| if output >= 0.0 { output } else { slope * output })
+                .collect(),
+        }
+    }
+
+    /// Derivative of the activation with respect to its pre-activation input `z`.
+    ///
+    /// Softmax has no standalone derivative and `Network::build` rejects it
+    /// as a layer activation outright: `CostFunction::CCE` applies softmax
+    /// internally to the final Dense layer's logits, both for the reported
+    /// cost and for the `delta = softmax(z) - target` backprop gradient,
+    /// skipping the ill-conditioned Jacobian entirely.
+    pub fn derivative(&self, pre_activation: &Vec<f64>) -> Vec<f64> {
+        match self {
+            ActivationFunction::Linear => pre_activation.iter().map(|_| 1.0).collect(),
+            ActivationFunction::ReLU => pre_activation
+                .iter()
+                .map(|&z| if z > 0.0 { 1.0 } else { 0.0 })
+                .collect(),
+            ActivationFunction::Sigmoid => {
+                let activated = self.apply(pre_activation);
+                activated.iter().map(|&s| s * (1.0 - s)).collect()
+            }
+            ActivationFunction::Softmax => panic!(
+                "Softmax has no standalone derivative; pair it with CostFunction::CCE instead"
+            ),
+            ActivationFunction::Tanh => {
+                let activated = self.apply(pre_activation);
+                activated.iter().map(|&t| 1.0 - t * t).collect()
+            }
+            ActivationFunction::LeakyReLU(slope) | ActivationFunction::PReLU(slope) => {
+                pre_activation
+                    .iter()
+                    .map(|&z| if z >= 0.0 { 1.0 } else { *slope })
+                    .collect()
+            }
+        }
+    }
+
+    /// The initialization strategy a freshly built layer should use when
+    /// none is given explicitly.
+    pub fn default_initialization(&self) -> Initialization {
+        match self {
+            ActivationFunction::ReLU => Initialization::He,
+            ActivationFunction::Sigmoid
+            | ActivationFunction::Softmax
+            | ActivationFunction::Tanh => Initialization::Xavier,
+            ActivationFunction::LeakyReLU(_) | ActivationFunction::PReLU(_) => Initialization::He,
+            ActivationFunction::Linear => Initialization::Uniform,
         }
     }
 }
@@ -71,7 +228,29 @@ impl Default for ActivationFunction {
     }
 }
 
+impl Initialization {
+    /// Draws a single weight/bias value for a neuron with `fan_in` inputs.
+    pub fn sample(&self, fan_in: usize) -> f64 {
+        match self {
+            Initialization::Uniform => Neuron::random(),
+            Initialization::Xavier => Self::normal(f64::sqrt(1.0 / fan_in as f64)),
+            Initialization::He => Self::normal(f64::sqrt(2.0 / fan_in as f64)),
+        }
+    }
+
+    fn normal(std_dev: f64) -> f64 {
+        use rand_distr::{Distribution, Normal};
+
+        Normal::new(0.0, std_dev)
+            .unwrap()
+            .sample(&mut rand::thread_rng())
+    }
+}
+
 impl CostFunction {
+    /// For `CCE`, `outputs` are expected to be the final Dense layer's raw
+    /// logits (activation `Linear`) — softmax is applied here, not by the
+    /// layer itself, so it is never applied twice to the same logits.
     pub fn apply(&self, outputs: &Vec<f64>, targets: Vec<f64>) -> f64 {
         match self {
             CostFunction::CCE => -ActivationFunction::Softmax
@@ -102,10 +281,13 @@ impl Neuron {
         rand::random::<f64>() * 2.0 - 1.0
     }
 
-    pub fn new(size: usize) -> Self {
+    pub fn new(size: usize, initialization: Initialization) -> Self {
         Self {
-            weights: (0..size).into_iter().map(|_| Self::random()).collect(),
-            bias: Self::random(),
+            weights: (0..size)
+                .into_iter()
+                .map(|_| initialization.sample(size))
+                .collect(),
+            bias: initialization.sample(size),
             output: Default::default(),
         }
     }
@@ -138,16 +320,44 @@ impl Genetic for Neuron {
         });
         self.bias += Self::random() * alpha;
     }
+
+    fn crossover(a: &Self, b: &Self) -> Self {
+        assert_eq!(
+            a.weights.len(),
+            b.weights.len(),
+            "Cannot cross neurons with a differing number of weights"
+        );
+
+        Self {
+            weights: a
+                .weights
+                .iter()
+                .zip(&b.weights)
+                .map(|(&a, &b)| if rand::random::<bool>() { a } else { b })
+                .collect(),
+            bias: if rand::random::<bool>() { a.bias } else { b.bias },
+            output: Default::default(),
+        }
+    }
 }
 
 impl Layer {
-    pub fn new(input_size: usize, size: usize, function: ActivationFunction) -> Self {
+    pub fn new(
+        input_size: usize,
+        size: usize,
+        function: ActivationFunction,
+        initialization: Initialization,
+    ) -> Self {
         Self {
             neurons: (0..size)
                 .into_iter()
-                .map(|_| Neuron::new(input_size))
+                .map(|_| Neuron::new(input_size, initialization))
                 .collect(),
             function,
+            last_input: Vec::new(),
+            last_output: Vec::new(),
+            pending_weight_grads: Vec::new(),
+            pending_bias_grads: Vec::new(),
         }
     }
 
@@ -159,6 +369,16 @@ impl Layer {
         self.neurons.iter().map(|neuron| neuron.output).collect()
     }
 
+    /// The activations produced by the most recent `forward` call.
+    pub fn activations(&self) -> &Vec<f64> {
+        &self.last_output
+    }
+
+    /// The inputs fed into the most recent `forward` call.
+    pub fn input(&self) -> &Vec<f64> {
+        &self.last_input
+    }
+
     pub fn forward(&mut self, inputs: &Vec<f64>) -> Vec<f64> {
         let weighted_sums = self
             .neurons
@@ -167,43 +387,694 @@ impl Layer {
             .collect();
 
         let activations = self.function.apply(&weighted_sums);
+
+        self.last_input = inputs.clone();
+        self.last_output = activations.clone();
+
         activations
     }
+
+    /// Computes `dL/dz` from `output_gradient` (`dL/da`) using this layer's
+    /// local activation derivative, then delegates to `backward_from_delta`.
+    pub fn backward(&mut self, output_gradient: &Vec<f64>) -> Vec<f64> {
+        let derivative = self.function.derivative(&self.outputs());
+        let delta: Vec<f64> = output_gradient
+            .iter()
+            .zip(&derivative)
+            .map(|(gradient, derivative)| gradient * derivative)
+            .collect();
+
+        self.backward_from_delta(&delta)
+    }
+
+    /// Accumulates weight/bias gradients from an already-computed `dL/dz`
+    /// and returns `dL/d(input)` for the previous layer. Used directly by a
+    /// `Linear` output layer paired with `CostFunction::CCE`, where `delta`
+    /// (`softmax(z) - target`) already folds in the combined derivative.
+    pub fn backward_from_delta(&mut self, delta: &Vec<f64>) -> Vec<f64> {
+        if self.pending_weight_grads.is_empty() {
+            self.pending_weight_grads = self
+                .neurons
+                .iter()
+                .map(|neuron| vec![0.0; neuron.weights.len()])
+                .collect();
+            self.pending_bias_grads = vec![0.0; self.neurons.len()];
+        }
+
+        for (neuron_index, &neuron_delta) in delta.iter().enumerate() {
+            for (weight_index, &input) in self.last_input.iter().enumerate() {
+                self.pending_weight_grads[neuron_index][weight_index] += neuron_delta * input;
+            }
+            self.pending_bias_grads[neuron_index] += neuron_delta;
+        }
+
+        let mut input_gradient = vec![0.0; self.last_input.len()];
+        for (input_index, gradient) in input_gradient.iter_mut().enumerate() {
+            *gradient = self
+                .neurons
+                .iter()
+                .zip(delta)
+                .map(|(neuron, &neuron_delta)| neuron.weights[input_index] * neuron_delta)
+                .sum();
+        }
+
+        input_gradient
+    }
+
+    /// Applies the gradients accumulated since the last call, averaged over
+    /// `batch_size`, then resets them for the next mini-batch.
+    pub fn apply_gradients(&mut self, learning_rate: f64, batch_size: f64) {
+        for (neuron, (weight_gradients, bias_gradient)) in self
+            .neurons
+            .iter_mut()
+            .zip(self.pending_weight_grads.iter().zip(&self.pending_bias_grads))
+        {
+            for (weight, weight_gradient) in neuron.weights.iter_mut().zip(weight_gradients) {
+                *weight -= learning_rate * weight_gradient / batch_size;
+            }
+            neuron.bias -= learning_rate * bias_gradient / batch_size;
+        }
+
+        self.pending_weight_grads.clear();
+        self.pending_bias_grads.clear();
+    }
 }
 impl Genetic for Layer {
     fn mutate(&mut self, alpha: f64) {
         self.neurons
             .iter_mut()
             .for_each(|neuron| neuron.mutate(alpha));
+
+        if let ActivationFunction::PReLU(slope) = &mut self.function {
+            *slope += Neuron::random() * alpha;
+        }
+    }
+
+    fn crossover(a: &Self, b: &Self) -> Self {
+        assert_eq!(
+            a.neurons.len(),
+            b.neurons.len(),
+            "Cannot cross layers with a differing number of neurons"
+        );
+
+        Self {
+            neurons: a
+                .neurons
+                .iter()
+                .zip(&b.neurons)
+                .map(|(a, b)| Neuron::crossover(a, b))
+                .collect(),
+            function: a.function.clone(),
+            last_input: Vec::new(),
+            last_output: Vec::new(),
+            pending_weight_grads: Vec::new(),
+            pending_bias_grads: Vec::new(),
+        }
+    }
+}
+
+impl Conv2D {
+    pub fn new(
+        input_shape: Shape,
+        kernel_size: usize,
+        kernel_count: usize,
+        stride: usize,
+        padding: usize,
+        function: ActivationFunction,
+        initialization: Initialization,
+    ) -> Self {
+        let output_height = (input_shape.height + 2 * padding - kernel_size) / stride + 1;
+        let output_width = (input_shape.width + 2 * padding - kernel_size) / stride + 1;
+        let output_shape = Shape::new(output_height, output_width, kernel_count);
+
+        let fan_in = kernel_size * kernel_size * input_shape.channels;
+
+        Self {
+            kernels: (0..kernel_count)
+                .map(|_| Kernel {
+                    weights: (0..fan_in).map(|_| initialization.sample(fan_in)).collect(),
+                    bias: initialization.sample(fan_in),
+                })
+                .collect(),
+            kernel_size,
+            stride,
+            padding,
+            function,
+            input_shape,
+            output_shape,
+            last_input: Vec::new(),
+            last_pre_activation: Vec::new(),
+            last_output: Vec::new(),
+            pending_kernel_grads: Vec::new(),
+            pending_bias_grads: Vec::new(),
+        }
+    }
+
+    pub fn output_shape(&self) -> Shape {
+        self.output_shape
+    }
+
+    pub fn activations(&self) -> &Vec<f64> {
+        &self.last_output
+    }
+
+    /// Maps an output position and a kernel offset back to a flat index
+    /// into the (possibly padded) input, or `None` if it falls in padding.
+    fn input_index(&self, out_y: usize, out_x: usize, ky: usize, kx: usize, channel: usize) -> Option<usize> {
+        let in_y = (out_y * self.stride + ky) as isize - self.padding as isize;
+        let in_x = (out_x * self.stride + kx) as isize - self.padding as isize;
+
+        if in_y < 0 || in_x < 0 || in_y as usize >= self.input_shape.height || in_x as usize >= self.input_shape.width {
+            return None;
+        }
+
+        Some(self.input_shape.index(in_y as usize, in_x as usize, channel))
+    }
+
+    pub fn forward(&mut self, input: &Vec<f64>) -> Vec<f64> {
+        let mut pre_activation = vec![0.0; self.output_shape.size()];
+
+        for out_y in 0..self.output_shape.height {
+            for out_x in 0..self.output_shape.width {
+                for (kernel_index, kernel) in self.kernels.iter().enumerate() {
+                    let mut sum = kernel.bias;
+
+                    for ky in 0..self.kernel_size {
+                        for kx in 0..self.kernel_size {
+                            for channel in 0..self.input_shape.channels {
+                                if let Some(input_index) =
+                                    self.input_index(out_y, out_x, ky, kx, channel)
+                                {
+                                    let weight_index = (ky * self.kernel_size + kx)
+                                        * self.input_shape.channels
+                                        + channel;
+                                    sum += input[input_index] * kernel.weights[weight_index];
+                                }
+                            }
+                        }
+                    }
+
+                    pre_activation[self.output_shape.index(out_y, out_x, kernel_index)] = sum;
+                }
+            }
+        }
+
+        let output = self.function.apply(&pre_activation);
+
+        self.last_input = input.clone();
+        self.last_pre_activation = pre_activation;
+        self.last_output = output.clone();
+
+        output
+    }
+
+    pub fn backward(&mut self, output_gradient: &Vec<f64>) -> Vec<f64> {
+        let derivative = self.function.derivative(&self.last_pre_activation);
+        let delta: Vec<f64> = output_gradient
+            .iter()
+            .zip(&derivative)
+            .map(|(gradient, derivative)| gradient * derivative)
+            .collect();
+
+        if self.pending_kernel_grads.is_empty() {
+            self.pending_kernel_grads = self
+                .kernels
+                .iter()
+                .map(|kernel| vec![0.0; kernel.weights.len()])
+                .collect();
+            self.pending_bias_grads = vec![0.0; self.kernels.len()];
+        }
+
+        let mut input_gradient = vec![0.0; self.input_shape.size()];
+
+        for out_y in 0..self.output_shape.height {
+            for out_x in 0..self.output_shape.width {
+                for (kernel_index, kernel) in self.kernels.iter().enumerate() {
+                    let neuron_delta = delta[self.output_shape.index(out_y, out_x, kernel_index)];
+                    self.pending_bias_grads[kernel_index] += neuron_delta;
+
+                    for ky in 0..self.kernel_size {
+                        for kx in 0..self.kernel_size {
+                            for channel in 0..self.input_shape.channels {
+                                if let Some(input_index) =
+                                    self.input_index(out_y, out_x, ky, kx, channel)
+                                {
+                                    let weight_index = (ky * self.kernel_size + kx)
+                                        * self.input_shape.channels
+                                        + channel;
+
+                                    self.pending_kernel_grads[kernel_index][weight_index] +=
+                                        neuron_delta * self.last_input[input_index];
+                                    input_gradient[input_index] +=
+                                        neuron_delta * kernel.weights[weight_index];
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        input_gradient
+    }
+
+    pub fn apply_gradients(&mut self, learning_rate: f64, batch_size: f64) {
+        for (kernel, (weight_gradients, bias_gradient)) in self
+            .kernels
+            .iter_mut()
+            .zip(self.pending_kernel_grads.iter().zip(&self.pending_bias_grads))
+        {
+            for (weight, weight_gradient) in kernel.weights.iter_mut().zip(weight_gradients) {
+                *weight -= learning_rate * weight_gradient / batch_size;
+            }
+            kernel.bias -= learning_rate * bias_gradient / batch_size;
+        }
+
+        self.pending_kernel_grads.clear();
+        self.pending_bias_grads.clear();
+    }
+}
+impl Genetic for Conv2D {
+    fn mutate(&mut self, alpha: f64) {
+        self.kernels.iter_mut().for_each(|kernel| {
+            kernel.weights.iter_mut().for_each(|weight| {
+                *weight += Neuron::random() * alpha;
+            });
+            kernel.bias += Neuron::random() * alpha;
+        });
+
+        if let ActivationFunction::PReLU(slope) = &mut self.function {
+            *slope += Neuron::random() * alpha;
+        }
+    }
+
+    fn crossover(a: &Self, b: &Self) -> Self {
+        assert_eq!(
+            a.kernels.len(),
+            b.kernels.len(),
+            "Cannot cross Conv2D layers with a differing number of kernels"
+        );
+
+        Self {
+            kernels: a
+                .kernels
+                .iter()
+                .zip(&b.kernels)
+                .map(|(a, b)| {
+                    assert_eq!(
+                        a.weights.len(),
+                        b.weights.len(),
+                        "Cannot cross kernels with a differing number of weights"
+                    );
+
+                    Kernel {
+                        weights: a
+                            .weights
+                            .iter()
+                            .zip(&b.weights)
+                            .map(|(&a, &b)| if rand::random::<bool>() { a } else { b })
+                            .collect(),
+                        bias: if rand::random::<bool>() { a.bias } else { b.bias },
+                    }
+                })
+                .collect(),
+            kernel_size: a.kernel_size,
+            stride: a.stride,
+            padding: a.padding,
+            function: a.function.clone(),
+            input_shape: a.input_shape,
+            output_shape: a.output_shape,
+            last_input: Vec::new(),
+            last_pre_activation: Vec::new(),
+            last_output: Vec::new(),
+            pending_kernel_grads: Vec::new(),
+            pending_bias_grads: Vec::new(),
+        }
+    }
+}
+
+impl MaxPool2D {
+    pub fn new(input_shape: Shape, pool_size: usize, stride: usize) -> Self {
+        let output_height = (input_shape.height - pool_size) / stride + 1;
+        let output_width = (input_shape.width - pool_size) / stride + 1;
+
+        Self {
+            pool_size,
+            stride,
+            input_shape,
+            output_shape: Shape::new(output_height, output_width, input_shape.channels),
+            max_indices: Vec::new(),
+            last_output: Vec::new(),
+        }
+    }
+
+    pub fn output_shape(&self) -> Shape {
+        self.output_shape
+    }
+
+    pub fn activations(&self) -> &Vec<f64> {
+        &self.last_output
+    }
+
+    pub fn forward(&mut self, input: &Vec<f64>) -> Vec<f64> {
+        let mut output = vec![0.0; self.output_shape.size()];
+        let mut max_indices = vec![0usize; self.output_shape.size()];
+
+        for out_y in 0..self.output_shape.height {
+            for out_x in 0..self.output_shape.width {
+                for channel in 0..self.output_shape.channels {
+                    let mut best_index = self.input_shape.index(
+                        out_y * self.stride,
+                        out_x * self.stride,
+                        channel,
+                    );
+                    let mut best_value = input[best_index];
+
+                    for py in 0..self.pool_size {
+                        for px in 0..self.pool_size {
+                            let index = self.input_shape.index(
+                                out_y * self.stride + py,
+                                out_x * self.stride + px,
+                                channel,
+                            );
+                            if input[index] > best_value {
+                                best_value = input[index];
+                                best_index = index;
+                            }
+                        }
+                    }
+
+                    let output_index = self.output_shape.index(out_y, out_x, channel);
+                    output[output_index] = best_value;
+                    max_indices[output_index] = best_index;
+                }
+            }
+        }
+
+        self.max_indices = max_indices;
+        self.last_output = output.clone();
+        output
+    }
+
+    pub fn backward(&mut self, output_gradient: &Vec<f64>) -> Vec<f64> {
+        let mut input_gradient = vec![0.0; self.input_shape.size()];
+
+        for (output_index, &input_index) in self.max_indices.iter().enumerate() {
+            input_gradient[input_index] += output_gradient[output_index];
+        }
+
+        input_gradient
+    }
+}
+impl Genetic for MaxPool2D {
+    fn mutate(&mut self, _alpha: f64) {
+        // MaxPool2D has no learnable parameters.
+    }
+
+    fn crossover(a: &Self, _b: &Self) -> Self {
+        Self {
+            pool_size: a.pool_size,
+            stride: a.stride,
+            input_shape: a.input_shape,
+            output_shape: a.output_shape,
+            max_indices: Vec::new(),
+            last_output: Vec::new(),
+        }
+    }
+}
+
+impl NetworkLayer {
+    pub fn output_shape(&self) -> Shape {
+        match self {
+            NetworkLayer::Dense(layer) => Shape::new(1, layer.get_size(), 1),
+            NetworkLayer::Conv2D(conv) => conv.output_shape(),
+            NetworkLayer::MaxPool2D(pool) => pool.output_shape(),
+        }
+    }
+
+    /// Short label used by `Network::summary`.
+    fn kind_name(&self) -> &'static str {
+        match self {
+            NetworkLayer::Dense(_) => "Dense",
+            NetworkLayer::Conv2D(_) => "Conv2D",
+            NetworkLayer::MaxPool2D(_) => "MaxPool2D",
+        }
+    }
+
+    /// The activation function driving this layer, if it has one
+    /// (`MaxPool2D` has no learnable or activated units).
+    fn activation(&self) -> Option<&ActivationFunction> {
+        match self {
+            NetworkLayer::Dense(layer) => Some(&layer.function),
+            NetworkLayer::Conv2D(conv) => Some(&conv.function),
+            NetworkLayer::MaxPool2D(_) => None,
+        }
+    }
+
+    /// The pre-activation weighted sums `z` of the most recent `forward`
+    /// call. Used by `CostFunction::CCE` to apply softmax to the final
+    /// Dense layer's logits for the backprop gradient.
+    fn pre_activation(&self) -> Vec<f64> {
+        match self {
+            NetworkLayer::Dense(layer) => layer.outputs(),
+            _ => panic!("CostFunction::CCE requires the output layer to be Dense"),
+        }
+    }
+
+    /// Number of learnable weights and biases in this layer.
+    fn parameter_count(&self) -> usize {
+        match self {
+            NetworkLayer::Dense(layer) => layer
+                .neurons
+                .iter()
+                .map(|neuron| neuron.weights.len() + 1)
+                .sum(),
+            NetworkLayer::Conv2D(conv) => conv
+                .kernels
+                .iter()
+                .map(|kernel| kernel.weights.len() + 1)
+                .sum(),
+            NetworkLayer::MaxPool2D(_) => 0,
+        }
+    }
+
+    fn forward(&mut self, input: &Vec<f64>) -> Vec<f64> {
+        match self {
+            NetworkLayer::Dense(layer) => layer.forward(input),
+            NetworkLayer::Conv2D(conv) => conv.forward(input),
+            NetworkLayer::MaxPool2D(pool) => pool.forward(input),
+        }
+    }
+
+    fn activations(&self) -> Vec<f64> {
+        match self {
+            NetworkLayer::Dense(layer) => layer.activations().clone(),
+            NetworkLayer::Conv2D(conv) => conv.activations().clone(),
+            NetworkLayer::MaxPool2D(pool) => pool.activations().clone(),
+        }
+    }
+
+    fn backward(&mut self, output_gradient: &Vec<f64>) -> Vec<f64> {
+        match self {
+            NetworkLayer::Dense(layer) => layer.backward(output_gradient),
+            NetworkLayer::Conv2D(conv) => conv.backward(output_gradient),
+            NetworkLayer::MaxPool2D(pool) => pool.backward(output_gradient),
+        }
+    }
+
+    fn backward_from_delta(&mut self, delta: &Vec<f64>) -> Vec<f64> {
+        match self {
+            NetworkLayer::Dense(layer) => layer.backward_from_delta(delta),
+            _ => panic!("CostFunction::CCE requires the output layer to be Dense"),
+        }
+    }
+
+    fn apply_gradients(&mut self, learning_rate: f64, batch_size: f64) {
+        match self {
+            NetworkLayer::Dense(layer) => layer.apply_gradients(learning_rate, batch_size),
+            NetworkLayer::Conv2D(conv) => conv.apply_gradients(learning_rate, batch_size),
+            NetworkLayer::MaxPool2D(_) => {}
+        }
+    }
+}
+impl Genetic for NetworkLayer {
+    fn mutate(&mut self, alpha: f64) {
+        match self {
+            NetworkLayer::Dense(layer) => layer.mutate(alpha),
+            NetworkLayer::Conv2D(conv) => conv.mutate(alpha),
+            NetworkLayer::MaxPool2D(pool) => pool.mutate(alpha),
+        }
+    }
+
+    fn crossover(a: &Self, b: &Self) -> Self {
+        match (a, b) {
+            (NetworkLayer::Dense(a), NetworkLayer::Dense(b)) => {
+                NetworkLayer::Dense(Layer::crossover(a, b))
+            }
+            (NetworkLayer::Conv2D(a), NetworkLayer::Conv2D(b)) => {
+                NetworkLayer::Conv2D(Conv2D::crossover(a, b))
+            }
+            (NetworkLayer::MaxPool2D(a), NetworkLayer::MaxPool2D(b)) => {
+                NetworkLayer::MaxPool2D(MaxPool2D::crossover(a, b))
+            }
+            _ => panic!("Cannot cross layers of different kinds"),
+        }
     }
 }
 
 impl Network {
     pub fn output_size(&self) -> usize {
+        self.output_shape().size()
+    }
+
+    pub fn output_shape(&self) -> Shape {
         self.layers
             .last()
-            .map_or(self.input_size, |layer| layer.get_size())
+            .map_or(self.shape, |layer| layer.output_shape())
+    }
+}
+impl<Status> Network<Status> {
+    /// The `(input_size, output_size)` pair for each layer, in order, so
+    /// tooling can inspect a deserialized model's architecture without
+    /// reaching into private fields.
+    pub fn layer_shapes(&self) -> Vec<(usize, usize)> {
+        let mut shapes = Vec::with_capacity(self.layers.len());
+        let mut input_size = self.shape.size();
+
+        for layer in &self.layers {
+            let output_size = layer.output_shape().size();
+            shapes.push((input_size, output_size));
+            input_size = output_size;
+        }
+
+        shapes
+    }
+
+    /// Total number of trainable weights and biases across every layer.
+    pub fn parameter_count(&self) -> usize {
+        self.layers.iter().map(NetworkLayer::parameter_count).sum()
+    }
+
+    /// Prints a per-layer summary (kind, activation, input/output size, and
+    /// parameter count) plus the grand total of trainable parameters.
+    pub fn summary(&self) {
+        for (index, (layer, (input_size, output_size))) in
+            self.layers.iter().zip(self.layer_shapes()).enumerate()
+        {
+            println!(
+                "Layer {index}: {} activation={:?} {input_size} -> {output_size} ({} params)",
+                layer.kind_name(),
+                layer.activation(),
+                layer.parameter_count()
+            );
+        }
+
+        println!("Total trainable parameters: {}", self.parameter_count());
     }
 }
 impl Network<Construction> {
     pub fn new(input_size: usize) -> Self {
+        Self::with_shape(Shape::new(1, input_size, 1))
+    }
+
+    pub fn with_shape(shape: Shape) -> Self {
         Self {
-            input_size,
+            shape,
             cost_function: CostFunction::default(),
             layers: Vec::new(),
             marker: std::marker::PhantomData::<Construction>,
         }
     }
 
-    pub fn add_layer(mut self, size: usize, function: ActivationFunction) -> Self {
-        self.layers
-            .push(Layer::new(self.output_size(), size, function));
+    pub fn add_layer(self, size: usize, function: ActivationFunction) -> Self {
+        let initialization = function.default_initialization();
+        self.add_layer_with_init(size, function, initialization)
+    }
+
+    pub fn add_layer_with_init(
+        mut self,
+        size: usize,
+        function: ActivationFunction,
+        initialization: Initialization,
+    ) -> Self {
+        self.layers.push(NetworkLayer::Dense(Layer::new(
+            self.output_size(),
+            size,
+            function,
+            initialization,
+        )));
+        self
+    }
+
+    pub fn add_conv(
+        self,
+        kernel_count: usize,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        function: ActivationFunction,
+    ) -> Self {
+        let initialization = function.default_initialization();
+        self.add_conv_with_init(kernel_count, kernel_size, stride, padding, function, initialization)
+    }
+
+    pub fn add_conv_with_init(
+        mut self,
+        kernel_count: usize,
+        kernel_size: usize,
+        stride: usize,
+        padding: usize,
+        function: ActivationFunction,
+        initialization: Initialization,
+    ) -> Self {
+        self.layers.push(NetworkLayer::Conv2D(Conv2D::new(
+            self.output_shape(),
+            kernel_size,
+            kernel_count,
+            stride,
+            padding,
+            function,
+            initialization,
+        )));
+        self
+    }
+
+    pub fn add_pool(mut self, pool_size: usize, stride: usize) -> Self {
+        self.layers.push(NetworkLayer::MaxPool2D(MaxPool2D::new(
+            self.output_shape(),
+            pool_size,
+            stride,
+        )));
         self
     }
 
     pub fn build(self, cost_function: CostFunction) -> Network<Ready> {
+        for layer in &self.layers {
+            if let Some(function) = layer.activation() {
+                assert!(
+                    !matches!(function, ActivationFunction::Softmax),
+                    "ActivationFunction::Softmax cannot be used as a layer activation; \
+                     pair CostFunction::CCE with a Linear output layer instead, softmax \
+                     is applied internally"
+                );
+            }
+        }
+
+        if matches!(cost_function, CostFunction::CCE) {
+            assert!(
+                matches!(
+                    self.layers.last(),
+                    Some(NetworkLayer::Dense(Layer {
+                        function: ActivationFunction::Linear,
+                        ..
+                    }))
+                ),
+                "CostFunction::CCE requires a Dense output layer with a Linear activation"
+            );
+        }
+
         Network {
-            input_size: self.input_size,
+            shape: self.shape,
             cost_function,
             layers: self.layers,
             marker: std::marker::PhantomData::<Ready>,
@@ -220,14 +1091,86 @@ impl Network<Ready> {
 
         (Label::from(&inputs), inputs)
     }
-    pub fn train(&mut self) {
-        todo!()
+
+    /// Trains the network with mini-batch gradient descent, returning the
+    /// average cost of each epoch so callers can track convergence.
+    pub fn train(
+        &mut self,
+        dataset: &Dataset,
+        epochs: usize,
+        batch_size: usize,
+        learning_rate: f64,
+    ) -> Vec<f64> {
+        let mut history = Vec::with_capacity(epochs);
+
+        for _ in 0..epochs {
+            let mut total_cost = 0.0;
+
+            for batch in dataset.datapoints().chunks(batch_size.max(1)) {
+                for datapoint in batch {
+                    let (_, outputs) = self.run(datapoint);
+                    total_cost += self.cost_function.apply(&outputs, datapoint.targets());
+
+                    self.backward(datapoint);
+                }
+
+                let batch_len = batch.len() as f64;
+                for layer in self.layers.iter_mut() {
+                    layer.apply_gradients(learning_rate, batch_len);
+                }
+            }
+
+            history.push(total_cost / dataset.size() as f64);
+        }
+
+        history
+    }
+
+    /// Backpropagates the error for a single datapoint, accumulating
+    /// per-layer gradients that `apply_gradients` later applies.
+    ///
+    /// Assumes `self.run(datapoint)` has just been called, so every layer's
+    /// cached input/activations reflect this datapoint.
+    fn backward(&mut self, datapoint: &Datapoint) {
+        let targets = datapoint.targets();
+        let last_index = self.layers.len() - 1;
+
+        // `CostFunction::CCE` assumes the output layer emits raw logits
+        // (activation `Linear`, enforced by `Network::build`) and applies
+        // softmax here for the gradient, exactly as `CostFunction::apply`
+        // applies it for the cost, so softmax is never stacked on top of a
+        // layer activation.
+        let mut gradient = if matches!(self.cost_function, CostFunction::CCE) {
+            let logits = self.layers[last_index].pre_activation();
+            let probabilities = ActivationFunction::Softmax.apply(&logits);
+            let delta: Vec<f64> = probabilities
+                .iter()
+                .zip(&targets)
+                .map(|(probability, target)| probability - target)
+                .collect();
+
+            self.layers[last_index].backward_from_delta(&delta)
+        } else {
+            let activations = self.layers[last_index].activations();
+            let output_gradient: Vec<f64> = activations
+                .iter()
+                .zip(&targets)
+                .map(|(activation, target)| activation - target)
+                .collect();
+
+            self.layers[last_index].backward(&output_gradient)
+        };
+
+        for layer_index in (0..last_index).rev() {
+            gradient = self.layers[layer_index].backward(&gradient);
+        }
     }
-    pub fn cost(&mut self, dataset: Dataset) -> f64 {
+
+    pub fn cost(&mut self, dataset: &Dataset) -> f64 {
         let mut cost = 0.0;
 
         for datapoint in dataset.datapoints().iter() {
-            let (label, outputs) = self.run(datapoint);
+            let (_, outputs) = self.run(datapoint);
 
             let loss = self.cost_function.apply(&outputs, datapoint.targets());
             cost += loss;
@@ -250,4 +1193,24 @@ impl Genetic for Network<Ready> {
     fn mutate(&mut self, alpha: f64) {
         self.layers.iter_mut().for_each(|layer| layer.mutate(alpha));
     }
+
+    fn crossover(a: &Self, b: &Self) -> Self {
+        assert_eq!(
+            a.layers.len(),
+            b.layers.len(),
+            "Cannot cross networks with a differing number of layers"
+        );
+
+        Self {
+            shape: a.shape,
+            layers: a
+                .layers
+                .iter()
+                .zip(&b.layers)
+                .map(|(a, b)| NetworkLayer::crossover(a, b))
+                .collect(),
+            cost_function: a.cost_function.clone(),
+            marker: std::marker::PhantomData::<Ready>,
+        }
+    }
 }
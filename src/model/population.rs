@@ -0,0 +1,88 @@
+use super::network::{Network, Ready};
+use super::training::{Dataset, Genetic};
+
+/// A gradient-free training path: a pool of `Network`s bred and mutated
+/// against a `Dataset` generation over generation, the natural counterpart
+/// to the `Genetic`/`add_noise` setup already used for single-network
+/// mutation.
+///
+/// Each member's fitness is cached alongside it (`fitness_cache[i]`
+/// corresponds to `members[i]`) so an elite carried over unchanged from the
+/// previous generation isn't re-scored; only newly bred members, whose
+/// cache slot is `None`, are evaluated.
+pub struct Population {
+    members: Vec<Network<Ready>>,
+    fitness_cache: Vec<Option<f64>>,
+    elite_fraction: f64,
+    mutation_rate: f64,
+}
+
+impl Population {
+    pub fn new(members: Vec<Network<Ready>>, elite_fraction: f64, mutation_rate: f64) -> Self {
+        let fitness_cache = vec![None; members.len()];
+
+        Self {
+            members,
+            fitness_cache,
+            elite_fraction,
+            mutation_rate,
+        }
+    }
+
+    pub fn members(&self) -> &Vec<Network<Ready>> {
+        &self.members
+    }
+
+    /// Fitness is the negative cost: lower cost means a fitter network.
+    fn fitness(network: &mut Network<Ready>, dataset: &Dataset) -> f64 {
+        -network.cost(dataset)
+    }
+
+    /// Evolves the population against `dataset` for `generations` rounds.
+    /// Each generation evaluates every member not already covered by the
+    /// fitness cache, keeps the fittest fraction as elites, and fills the
+    /// rest of the population by crossing random elite pairs and mutating
+    /// the offspring.
+    pub fn evolve(&mut self, dataset: &Dataset, generations: usize) {
+        let population_size = self.members.len();
+
+        for _ in 0..generations {
+            let mut scored: Vec<(f64, Network<Ready>)> = self
+                .members
+                .drain(..)
+                .zip(self.fitness_cache.drain(..))
+                .map(|(mut network, cached)| {
+                    let fitness = cached.unwrap_or_else(|| Self::fitness(&mut network, dataset));
+                    (fitness, network)
+                })
+                .collect();
+            scored.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+            let elite_count = ((population_size as f64) * self.elite_fraction)
+                .round()
+                .max(1.0) as usize;
+
+            let mut next_generation = Vec::with_capacity(population_size);
+            let mut next_fitness_cache = Vec::with_capacity(population_size);
+
+            for (fitness, network) in scored.into_iter().take(elite_count) {
+                next_generation.push(network);
+                next_fitness_cache.push(Some(fitness));
+            }
+
+            while next_generation.len() < population_size {
+                let a = &next_generation[(rand::random::<f64>() * elite_count as f64) as usize];
+                let b = &next_generation[(rand::random::<f64>() * elite_count as f64) as usize];
+
+                let mut child = Network::crossover(a, b);
+                child.mutate(self.mutation_rate);
+
+                next_generation.push(child);
+                next_fitness_cache.push(None);
+            }
+
+            self.members = next_generation;
+            self.fitness_cache = next_fitness_cache;
+        }
+    }
+}
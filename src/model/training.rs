@@ -4,6 +4,13 @@ use strum_macros::EnumCount;
 
 pub trait Genetic {
     fn mutate(&mut self, alpha: f64);
+
+    /// Breeds two parents into a child by picking each gene (weight or bias)
+    /// from either parent with 50% probability. Parents must share identical
+    /// shapes; implementations should assert this.
+    fn crossover(a: &Self, b: &Self) -> Self
+    where
+        Self: Sized;
 }
 
 #[derive(Debug)]
@@ -11,7 +18,7 @@ pub struct Dataset {
     datapoints: Vec<Datapoint>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Datapoint {
     inputs: Vec<f64>,
     label: Label,
@@ -30,9 +37,78 @@ impl Dataset {
     pub fn size(&self) -> usize {
         self.datapoints.len()
     }
+
+    /// Builds a dataset by loading every image in `real_dir` as `Label::Real`
+    /// and every image in `fake_dir` as `Label::Fake`.
+    pub fn from_dirs(real_dir: &str, fake_dir: &str) -> Self {
+        let mut datapoints = Self::load_labeled_dir(real_dir, Label::Real);
+        datapoints.extend(Self::load_labeled_dir(fake_dir, Label::Fake));
+
+        Self { datapoints }
+    }
+
+    /// Loads every decodable image in `dir`, skipping files that aren't one
+    /// (stray `.DS_Store`, `.txt` notes, etc.) rather than aborting the
+    /// whole dataset over a single junk file.
+    fn load_labeled_dir(dir: &str, label: Label) -> Vec<Datapoint> {
+        std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_file())
+            .filter_map(|path| image::open(path).ok())
+            .map(|image| Datapoint::with_label(image, label))
+            .collect()
+    }
+
+    /// Shuffles the dataset and partitions it into a train/test split, where
+    /// `ratio` is the fraction kept for training.
+    pub fn split(&self, ratio: f64) -> (Dataset, Dataset) {
+        let mut indices: Vec<usize> = (0..self.datapoints.len()).collect();
+
+        for i in (1..indices.len()).rev() {
+            let j = (rand::random::<f64>() * (i + 1) as f64) as usize;
+            indices.swap(i, j);
+        }
+
+        let split_at = (indices.len() as f64 * ratio).round() as usize;
+
+        let train = indices[..split_at]
+            .iter()
+            .map(|&index| self.datapoints[index].clone())
+            .collect();
+        let test = indices[split_at..]
+            .iter()
+            .map(|&index| self.datapoints[index].clone())
+            .collect();
+
+        (Dataset { datapoints: train }, Dataset { datapoints: test })
+    }
 }
 
 impl Datapoint {
+    /// Flattens an image into an input vector, tagged with an explicit
+    /// `Label` rather than assuming `Label::Real`.
+    pub fn with_label(image: DynamicImage, label: Label) -> Self {
+        let mut inputs = Vec::new();
+
+        let (width, height) = image.dimensions();
+
+        (0..height).for_each(|y| {
+            (0..width).for_each(|x| {
+                let channels = &mut image
+                    .get_pixel(x, y)
+                    .0
+                    .iter()
+                    .map(|&channel| channel as f64 / 256.0)
+                    .collect();
+
+                inputs.append(channels);
+            });
+        });
+
+        Self { label, inputs }
+    }
+
     pub fn inputs(&self) -> &Vec<f64> {
         &self.inputs
     }
@@ -100,27 +176,7 @@ impl Into<DynamicImage> for &Datapoint {
 }
 impl From<DynamicImage> for Datapoint {
     fn from(value: DynamicImage) -> Self {
-        let mut inputs = Vec::new();
-
-        let (width, height) = value.dimensions();
-
-        (0..height).for_each(|y| {
-            (0..width).for_each(|x| {
-                let channels = &mut value
-                    .get_pixel(x, y)
-                    .0
-                    .iter()
-                    .map(|&channel| channel as f64 / 256.0)
-                    .collect();
-
-                inputs.append(channels);
-            });
-        });
-
-        Self {
-            label: Label::Real,
-            inputs,
-        }
+        Self::with_label(value, Label::Real)
     }
 }
 